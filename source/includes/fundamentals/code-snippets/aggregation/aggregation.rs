@@ -75,6 +75,31 @@ async fn main() -> mongodb::error::Result<()> {
     }
     // end-popular-agg
 
+    // begin-typed-agg
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct GenreCount {
+        #[serde(rename = "_id")]
+        genre: String,
+        number: i32,
+    }
+
+    let popularity_pipeline = vec![
+        doc! { "$unwind" : "$genre_interests" },
+        doc! { "$group" : doc! { "_id" : "$genre_interests" , "number" : doc! { "$sum" : 1 } } },
+        doc! { "$sort" : doc! { "number" : -1 } },
+        doc! { "$limit": 3 }
+    ];
+
+    let mut results = my_coll
+        .aggregate(popularity_pipeline)
+        .with_type::<GenreCount>()
+        .await?;
+    while let Some(result) = results.try_next().await? {
+        println!("* {:?}", result);
+    }
+    // end-typed-agg
+
     Ok(())
 }
 
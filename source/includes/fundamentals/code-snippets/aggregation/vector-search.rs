@@ -1,5 +1,7 @@
 #![recursion_limit = "2560"]
 use bson::binary::Vector;
+use bson::oid::ObjectId;
+use std::collections::HashMap;
 use mongodb::Collection;
 use mongodb::{bson::doc, Client};
 
@@ -75,5 +77,94 @@ async fn main() -> mongodb::error::Result<()> {
     }
     // end-score-query
 
+    // start-filter-query
+    #[derive(Debug, Deserialize)]
+    struct ScoredMovie {
+        title: String,
+        year: i32,
+        score: f64,
+    }
+
+    let pipeline = vec![
+        doc! {
+            "$vectorSearch": doc! {
+                "queryVector": &query_vector,
+                "path": "plot_embedding",
+                "filter": doc! { "year": doc! { "$gte": 2000 } },
+                "exact": true,
+                "index": "vector_index",
+                "limit": 3
+            }
+        },
+        doc! {
+            "$project": doc! {
+                "_id": 0,
+                "title": 1,
+                "year": 1,
+                "score": doc! { "$meta": "vectorSearchScore" },
+            }
+        },
+    ];
+
+    let mut results = my_coll.aggregate(pipeline).with_type::<ScoredMovie>().await?;
+    while let Some(result) = results.try_next().await? {
+        println!("{:?}", result);
+    }
+    // end-filter-query
+
+    // start-hybrid-query
+    #[derive(Debug, Deserialize)]
+    struct MovieTitle {
+        #[serde(rename = "_id")]
+        id: ObjectId,
+        title: String,
+    }
+
+    let vector_pipeline = vec![
+        doc! {
+            "$vectorSearch": doc! {
+                "queryVector": &query_vector,
+                "path": "plot_embedding",
+                "numCandidates": 150,
+                "index": "vector_index",
+                "limit": 20
+            }
+        },
+        doc! { "$project": doc! { "title": 1 } },
+    ];
+    let text_pipeline = vec![
+        doc! {
+            "$search": doc! {
+                "index": "default",
+                "text": doc! { "query": "time travel", "path": "plot" }
+            }
+        },
+        doc! { "$limit": 20 },
+        doc! { "$project": doc! { "title": 1 } },
+    ];
+
+    let mut fused: HashMap<ObjectId, (String, f64)> = HashMap::new();
+    for (pipeline, weight) in [(vector_pipeline, 1.0), (text_pipeline, 1.0)] {
+        let results: Vec<MovieTitle> = my_coll
+            .aggregate(pipeline)
+            .with_type::<MovieTitle>()
+            .await?
+            .try_collect()
+            .await?;
+
+        for (i, movie) in results.into_iter().enumerate() {
+            let rank = (i + 1) as f64;
+            let entry = fused.entry(movie.id).or_insert((movie.title, 0.0));
+            entry.1 += weight / (60.0 + rank);
+        }
+    }
+
+    let mut ranked: Vec<(String, f64)> = fused.into_values().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for (title, score) in ranked.iter().take(5) {
+        println!("{}: {}", title, score);
+    }
+    // end-hybrid-query
+
     Ok(())
 }
@@ -113,6 +113,34 @@ async fn main() -> mongodb::error::Result<()> {
     println!("Modified documents: {}", result.modified_count);
     // end-replace
 
+    // begin-upsert-structs
+    let mushrooms: Collection<Mushroom> = client.database("db").collection("mushrooms");
+
+    let records = vec![
+        Mushroom {
+            name: "cremini".to_string(),
+            color: "light brown".to_string(),
+            edible: true,
+        },
+        Mushroom {
+            name: "enoki".to_string(),
+            color: "white".to_string(),
+            edible: true,
+        },
+    ];
+
+    let mut models = Vec::new();
+    for record in &records {
+        let mut model = mushrooms.replace_one_model(doc! { "name": &record.name }, record)?;
+        model.upsert = Some(true);
+        models.push(model);
+    }
+
+    let result = client.bulk_write(models).await?;
+    println!("Matched documents: {}", result.matched_count);
+    println!("Upserted documents: {}", result.upserted_count);
+    // end-upsert-structs
+
     // begin-update
     let mushrooms: Collection<Document> = client.database("db").collection("mushrooms");
 
@@ -73,5 +73,26 @@ async fn main() -> mongodb::error::Result<()> {
     println!("Document after replacement:\n{:?}", res);
     // end-find_one_and_replace
 
+    // begin-optimistic-concurrency
+    let filter = doc! { "name": "Samara Khan" };
+    if let Some(student) = my_coll.find_one(filter).await? {
+        let filter = doc! {
+            "_id": student.get("_id").cloned(),
+            "version": student.get("version").cloned()
+        };
+        let update =
+            doc! { "$set": doc! { "school": "Durango High School" },
+                   "$inc": doc! { "version": 1 } };
+
+        let res = my_coll.find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await?;
+        match res {
+            Some(doc) => println!("Updated document:\n{:?}", doc),
+            None => println!("Document was modified by another client"),
+        }
+    }
+    // end-optimistic-concurrency
+
     Ok(())
 }
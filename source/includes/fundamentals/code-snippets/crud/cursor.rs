@@ -1,7 +1,8 @@
 use futures::{ StreamExt, TryStreamExt };
-use mongodb::{ bson::doc, bson::Document, Client, Collection, error::Result, options::{ FindOptions, CursorType } };
+use mongodb::{ bson::{ doc, Bson, Document }, Client, Collection, error::Result, options::{ FindOptions, CursorType } };
 
 use serde::{ Deserialize, Serialize };
+use std::time::Duration;
 
 #[derive(Serialize, Deserialize, Debug)]
 struct Fruit {
@@ -9,6 +10,42 @@ struct Fruit {
     color: String
 }
 
+// start-tailable
+async fn tail_logs(logs: Collection<Document>) {
+    let mut last_id: Option<Bson> = None;
+
+    loop {
+        let filter = match &last_id {
+            Some(id) => doc! { "_id": { "$gt": id.clone() } },
+            None => doc! {},
+        };
+
+        match logs.find(filter)
+            .cursor_type(CursorType::TailableAwait)
+            .max_await_time(Duration::from_secs(10))
+            .await
+        {
+            Ok(mut cursor) => loop {
+                match cursor.try_next().await {
+                    Ok(Some(doc)) => {
+                        last_id = doc.get("_id").cloned();
+                        println!("{:?}", doc);
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        println!("Cursor error: {}", e);
+                        break;
+                    }
+                }
+            },
+            Err(e) => println!("Query error: {}", e),
+        }
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+// end-tailable
+
 #[tokio::main]
 async fn main() -> mongodb::error::Result<()> {
     // Replace the placeholder with your Atlas connection string
@@ -51,6 +88,24 @@ async fn main() -> mongodb::error::Result<()> {
 
     println!();
 
+    // start-indiv-borrowed
+    #[derive(Deserialize, Debug)]
+    struct FruitView<'a> {
+        name: &'a str,
+        color: &'a str
+    }
+
+    let mut cursor = my_coll
+        .find(doc! { "color": "red" })
+        .await?
+        .with_type::<FruitView>();
+    while cursor.advance().await? {
+        println!("{:?}", cursor.deserialize_current()?);
+    }
+    // end-indiv-borrowed
+
+    println!();
+
     // start-indiv-stream
     let mut cursor = my_coll.find(doc! { "color": "red" }).await?;
     println!("Output from next() iteration:");
@@ -89,5 +144,52 @@ async fn main() -> mongodb::error::Result<()> {
         .await?;
     // end-options
 
+    // start-read-ahead
+    let mut cursor = my_coll.find(doc! {}).batch_size(100).await?;
+    let (tx, mut rx) = tokio::sync::mpsc::channel(200);
+
+    tokio::spawn(async move {
+        while let Some(result) = cursor.next().await {
+            if tx.send(result).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(result) = rx.recv().await {
+        println!("{:?}", result?);
+    }
+    // end-read-ahead
+
+    // start-resumable-scan
+    let checkpoints: Collection<Document> = client.database("db").collection("checkpoints");
+    let fruits: Collection<Document> = my_coll.clone_with_type();
+
+    let checkpoint = checkpoints.find_one(doc! { "_id": "fruit-scan" }).await?;
+    let filter = match checkpoint.as_ref().and_then(|c| c.get("last_id")) {
+        Some(id) => doc! { "_id": { "$gt": id.clone() } },
+        None => doc! {},
+    };
+
+    let estimated_total = fruits.estimated_document_count().await?;
+    let mut processed = checkpoint.and_then(|c| c.get_i64("processed").ok()).unwrap_or(0);
+
+    let mut cursor = fruits.find(filter).sort(doc! { "_id": 1 }).await?;
+    while let Some(doc) = cursor.try_next().await? {
+        // Process the document here
+
+        processed += 1;
+        if processed % 1000 == 0 {
+            checkpoints.update_one(
+                doc! { "_id": "fruit-scan" },
+                doc! { "$set": { "last_id": doc.get("_id").cloned(), "processed": processed } }
+            ).upsert(true).await?;
+            println!("Processed {} of about {} documents", processed, estimated_total);
+        }
+    }
+
+    checkpoints.delete_one(doc! { "_id": "fruit-scan" }).await?;
+    // end-resumable-scan
+
     Ok(())
 }
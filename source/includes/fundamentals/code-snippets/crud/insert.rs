@@ -1,8 +1,9 @@
-use futures::TryStreamExt;
+use futures::{ stream, StreamExt, TryStreamExt };
 use mongodb::{
     bson::doc,
     Client, 
     Collection, 
+    error::ErrorKind,
     results::{ InsertOneResult, InsertManyResult },
     options::{ InsertOneOptions, InsertManyOptions }, 
 };
@@ -80,5 +81,38 @@ async fn main() -> mongodb::error::Result<()> {
     my_coll.insert_many(docs).ordered(false).await?;
     // end-unordered
 
+    // begin-insert-stream
+    let books = stream::iter((100..10_000).map(|id| Book {
+        _id: id,
+        title: format!("Book {}", id),
+        author: "".to_string()
+    }));
+
+    let coll_ref = &my_coll;
+    let mut results = books
+        .chunks(1000)
+        .map(|batch| async move {
+            let batch_len = batch.len();
+            (batch_len, coll_ref.insert_many(batch).ordered(false).await)
+        })
+        .buffer_unordered(4);
+
+    let mut inserted = 0;
+    while let Some((batch_len, result)) = results.next().await {
+        match result {
+            Ok(res) => inserted += res.inserted_ids.len(),
+            Err(e) => match *e.kind {
+                ErrorKind::InsertMany(ref failure) => {
+                    let failed = failure.write_errors.as_ref().map_or(0, |errors| errors.len());
+                    inserted += batch_len - failed;
+                    println!("Failed to insert some documents: {:?}", failure.write_errors);
+                }
+                _ => return Err(e),
+            },
+        }
+    }
+    println!("Inserted {} documents", inserted);
+    // end-insert-stream
+
     Ok(())
 }
@@ -93,5 +93,40 @@ while let Some(result) = cursor.try_next().await? {
 }
 // end-aggregation-example
 
+// Retrieves documents in pages of two by using the "length" and "_id" values of
+// the last document on each page to filter the next page.
+// start-range-pagination-example
+    let my_coll: Collection<Document> = my_coll.clone_with_type();
+    let mut filter = doc! {};
+    let mut page = 1;
+
+    loop {
+        let docs: Vec<Document> = my_coll
+            .find(filter)
+            .sort(doc! { "length": 1, "_id": 1 })
+            .limit(2)
+            .await?
+            .try_collect()
+            .await?;
+
+        let Some(last) = docs.last() else { break };
+
+        println!("Page {}:", page);
+        for doc in &docs {
+            println!("{} ({})", doc.get("name").unwrap(), doc.get("length").unwrap());
+        }
+
+        let last_length = last.get("length").cloned();
+        let last_id = last.get("_id").cloned();
+        filter = doc! {
+            "$or": [
+                { "length": { "$gt": last_length.clone() } },
+                { "length": last_length, "_id": { "$gt": last_id } }
+            ]
+        };
+        page += 1;
+    }
+// end-range-pagination-example
+
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 use bson::{doc, Document};
 use futures::TryStreamExt;
 use mongodb::{Client, Collection, SearchIndexModel, SearchIndexType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
 #[tokio::main]
 async fn main() -> mongodb::error::Result<()> {
@@ -44,6 +47,107 @@ async fn main() -> mongodb::error::Result<()> {
         .build();
     // end-avs-model
 
+    // begin-as-typed-model
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "camelCase")]
+    enum SearchField {
+        String,
+        Date,
+        Number,
+        Token,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct SearchMappings {
+        dynamic: bool,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        fields: BTreeMap<String, SearchField>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct SearchIndexDefinition {
+        mappings: SearchMappings,
+    }
+
+    let def = SearchIndexDefinition {
+        mappings: SearchMappings {
+            dynamic: false,
+            fields: BTreeMap::from([
+                ("title".to_string(), SearchField::String),
+                ("released".to_string(), SearchField::Date),
+            ]),
+        },
+    };
+
+    let idx_model = SearchIndexModel::builder()
+        .definition(bson::to_document(&def)?)
+        .name("search_typed_idx".to_string())
+        .index_type(SearchIndexType::Search)
+        .build();
+
+    let result = my_coll.create_search_index(idx_model).await?;
+    println!("Created Atlas Search index:\n{}", result);
+    // end-as-typed-model
+
+    // begin-avs-typed-model
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    enum VectorIndexField {
+        #[serde(rename_all = "camelCase")]
+        Vector {
+            path: String,
+            num_dimensions: i32,
+            similarity: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            quantization: Option<String>,
+        },
+        Filter {
+            path: String,
+        },
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct VectorIndexDefinition {
+        fields: Vec<VectorIndexField>,
+    }
+
+    let def = VectorIndexDefinition {
+        fields: vec![
+            VectorIndexField::Vector {
+                path: "plot_embedding".to_string(),
+                num_dimensions: 1536,
+                similarity: "euclidean".to_string(),
+                quantization: Some("scalar".to_string()),
+            },
+            VectorIndexField::Filter {
+                path: "year".to_string(),
+            },
+        ],
+    };
+
+    let idx_model = SearchIndexModel::builder()
+        .definition(bson::to_document(&def)?)
+        .name("vs_typed_idx".to_string())
+        .index_type(SearchIndexType::VectorSearch)
+        .build();
+
+    let result = my_coll.create_search_index(idx_model).await?;
+    println!("Created Atlas Vector Search index:\n{}", result);
+    // end-avs-typed-model
+
+    // begin-avs-typed-list
+    let mut cursor = my_coll.list_search_indexes().await?;
+    while let Some(index) = cursor.try_next().await? {
+        if index.get_str("name").ok() != Some("vs_typed_idx") {
+            continue;
+        }
+        if let Ok(latest) = index.get_document("latestDefinition") {
+            let def: VectorIndexDefinition = bson::from_document(latest.clone())?;
+            println!("{:?}", def);
+        }
+    }
+    // end-avs-typed-list
+
     // begin-atlas-create-one
     let idx_model = SearchIndexModel::builder()
         .definition(doc! { "mappings": doc! {"dynamic": true} })
@@ -85,6 +189,35 @@ async fn main() -> mongodb::error::Result<()> {
     }
     // end-atlas-list
 
+    // begin-atlas-await
+    let name = "example_index";
+    let deadline = Instant::now() + Duration::from_secs(300);
+
+    loop {
+        let indexes: Vec<Document> = my_coll.list_search_indexes().await?.try_collect().await?;
+        let index = indexes
+            .iter()
+            .find(|idx| idx.get_str("name").ok() == Some(name));
+
+        if let Some(index) = index {
+            if index.get_bool("queryable").unwrap_or(false) {
+                println!("Index {} is queryable", name);
+                break;
+            }
+            if index.get_str("status").ok() == Some("FAILED") {
+                println!("Index {} failed to build: {:?}", name, index.get("statusDetail"));
+                break;
+            }
+        }
+
+        if Instant::now() >= deadline {
+            println!("Timed out waiting for index {}", name);
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+    // end-atlas-await
+
     // begin-atlas-update
     let name = "vs_index";
     let updated_def = doc! {
@@ -1,4 +1,7 @@
-use bson::{doc, Document};
+use bson::{doc, DateTime, Document};
+use futures::TryStreamExt;
+use serde::Deserialize;
+use std::time::Duration;
 use mongodb::{
     bson,
     options::{ClusteredIndex, IndexOptions},
@@ -77,10 +80,107 @@ async fn main() -> mongodb::error::Result<()> {
         .build();
     // end-unique
 
+    let my_coll: Collection<Document> = client.database("sample_training").collection("zips");
+    // begin-build-progress
+    let index = IndexModel::builder().keys(doc! { "pop": 1 }).build();
+    let coll_ref = my_coll.clone();
+    let build = tokio::spawn(async move { coll_ref.create_index(index).await });
+
+    let admin_db = client.database("admin");
+    while !build.is_finished() {
+        let pipeline = vec![
+            doc! { "$currentOp": { "allUsers": true } },
+            doc! { "$match": { "$or": [
+                { "op": "command", "command.createIndexes": { "$exists": true } },
+                { "op": "none", "msg": { "$regex": "^Index Build" } }
+            ] } },
+        ];
+
+        let mut ops = admin_db.aggregate(pipeline).await?;
+        while let Some(op) = ops.try_next().await? {
+            if let (Some(opid), Some(msg)) = (op.get("opid"), op.get("msg")) {
+                println!("Operation {:?}: {:?}", opid, msg);
+            }
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    let idx = build.await.expect("index build task panicked")?;
+    println!("Created index:\n{}", idx.index_name);
+    // end-build-progress
+
+    my_coll.drop_index("pop_1".to_string()).await?;
+
+    // begin-index-stats
+    #[derive(Debug, Deserialize)]
+    struct IndexAccesses {
+        ops: i64,
+        since: DateTime,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct IndexUsage {
+        name: String,
+        accesses: IndexAccesses,
+    }
+
+    let mut cursor = my_coll
+        .aggregate(vec![doc! { "$indexStats": {} }])
+        .with_type::<IndexUsage>()
+        .await?;
+    while let Some(usage) = cursor.try_next().await? {
+        println!("{:?}", usage);
+    }
+    // end-index-stats
+
+    // begin-hide-index
+    let db = client.database("sample_training");
+    db.run_command(doc! {
+        "collMod": "zips",
+        "index": { "name": "city_1_pop_-1", "hidden": true }
+    }).await?;
+    // end-hide-index
+
     let my_coll: Collection<Document> = client.database("sample_training").collection("zips");
     // begin-drop
     my_coll.drop_index("city_1".to_string()).await?;
     // end-drop
 
+    // begin-compare
+    let spec = vec![
+        IndexModel::builder().keys(doc! { "city": 1 }).build(),
+        IndexModel::builder()
+            .keys(doc! { "city": 1, "pop": -1 })
+            .build(),
+    ];
+    let apply_changes = false;
+
+    let existing: Vec<IndexModel> = my_coll.list_indexes().await?.try_collect().await?;
+    let is_unique = |idx: &IndexModel| {
+        idx.options.as_ref().and_then(|opts| opts.unique).unwrap_or(false)
+    };
+
+    for model in &spec {
+        match existing.iter().find(|idx| idx.keys == model.keys) {
+            Some(idx) if is_unique(idx) != is_unique(model) => {
+                println!("Changed options: {}", model.keys);
+            }
+            Some(_) => {}
+            None => {
+                println!("Missing index: {}", model.keys);
+                if apply_changes {
+                    my_coll.create_index(model.clone()).await?;
+                }
+            }
+        }
+    }
+
+    for idx in &existing {
+        if idx.keys != doc! { "_id": 1 } && !spec.iter().any(|model| model.keys == idx.keys) {
+            println!("Extra index: {}", idx.keys);
+        }
+    }
+    // end-compare
+
     Ok(())
 }
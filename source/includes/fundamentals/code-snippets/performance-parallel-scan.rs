@@ -0,0 +1,41 @@
+let client = Client::with_uri_str("<connection string>").await?;
+let collection: Collection<Document> = client.database("items").collection("books");
+
+let pipeline = vec![
+    doc! { "$sample": { "size": 1000 } },
+    doc! { "$bucketAuto": { "groupBy": "$_id", "buckets": 4 } },
+];
+let buckets: Vec<Document> = collection.aggregate(pipeline).await?.try_collect().await?;
+
+let split_points: Vec<Bson> = buckets
+    .iter()
+    .skip(1)
+    .filter_map(|bucket| bucket.get_document("_id").ok()?.get("min").cloned())
+    .collect();
+
+let mut handles = Vec::new();
+for i in 0..=split_points.len() {
+    let mut range = Document::new();
+    if i > 0 {
+        range.insert("$gte", split_points[i - 1].clone());
+    }
+    if i < split_points.len() {
+        range.insert("$lt", split_points[i].clone());
+    }
+    let filter = if range.is_empty() { doc! {} } else { doc! { "_id": range } };
+
+    let collection_ref = collection.clone();
+    handles.push(task::spawn(async move {
+        let mut cursor = collection_ref.find(filter).await?;
+        let mut count = 0;
+        while let Some(_doc) = cursor.try_next().await? {
+            count += 1;
+        }
+        Ok::<_, mongodb::error::Error>(count)
+    }));
+}
+
+for (i, handle) in handles.into_iter().enumerate() {
+    let count = handle.await.expect("task panicked")?;
+    println!("Range {} contained {} documents", i, count);
+}
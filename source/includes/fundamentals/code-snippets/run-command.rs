@@ -1,4 +1,5 @@
-use mongodb::{ bson::{ doc, Document }, Client, Database };
+use mongodb::{ bson::{ self, doc, Bson, Document }, Client, Database };
+use serde::Deserialize;
 
 #[tokio::main]
 async fn main() -> mongodb::error::Result<()> {
@@ -20,5 +21,27 @@ async fn main() -> mongodb::error::Result<()> {
 
     println!("{}", result);
 
+    // start-explain-stats
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ExecutionStats {
+        n_returned: i64,
+        execution_time_millis: i64,
+        total_keys_examined: i64,
+        total_docs_examined: i64,
+    }
+
+    let explain_command = doc! {
+        "explain": { "find": "flowers", "filter": { "color": "purple" } },
+        "verbosity": "executionStats"
+    };
+
+    let result = my_db.run_command(explain_command).await?;
+    if let Some(Bson::Document(stats)) = result.get("executionStats") {
+        let stats: ExecutionStats = bson::from_document(stats.clone())?;
+        println!("{:?}", stats);
+    }
+    // end-explain-stats
+
     Ok(())
 }
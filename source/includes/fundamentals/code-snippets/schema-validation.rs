@@ -35,5 +35,39 @@ async fn main() -> mongodb::error::Result<()> {
         .await?;
     // end-schema-validation
 
+    // begin-change-validation
+    let survey_answers: Collection<Document> = db.collection("survey_answers");
+
+    survey_answers.update_many(
+        doc! { "schema_version": doc! { "$exists": false } },
+        doc! {
+            "$rename": doc! { "answer": "response" },
+            "$set": doc! { "schema_version": 2 }
+        }
+    ).await?;
+
+    let missing = survey_answers
+        .count_documents(doc! { "response": doc! { "$exists": false } })
+        .await?;
+    let level = if missing == 0 { "strict" } else { "moderate" };
+
+    db.run_command(doc! {
+        "collMod": "survey_answers",
+        "validator": doc! {
+            "$jsonSchema": doc! {
+                "bsonType": "object",
+                "title": "Response Value Validation",
+                "required": vec! [ "response" ],
+                "properties": doc! {
+                    "response": doc! {
+                        "enum": vec! [ "yes", "no" ],
+                    }
+                }
+            }
+        },
+        "validationLevel": level
+    }).await?;
+    // end-change-validation
+
     Ok(())
 }
\ No newline at end of file
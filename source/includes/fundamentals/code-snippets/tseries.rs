@@ -1,9 +1,22 @@
 use futures::TryStreamExt;
 use mongodb::{
+    bson::{ doc, DateTime },
     options::{ TimeseriesOptions, TimeseriesGranularity, CreateCollectionOptions },
     Client,
+    Collection,
 };
+use serde::{ Deserialize, Serialize };
 use std::env;
+use std::time::Duration;
+
+// begin-reading-struct
+#[derive(Debug, Serialize, Deserialize)]
+struct Reading {
+    timestamp: DateTime,
+    sensor: String,
+    precipitation_mm: f64,
+}
+// end-reading-struct
 
 #[tokio::main]
 async fn main() -> mongodb::error::Result<()> {
@@ -31,5 +44,91 @@ async fn main() -> mongodb::error::Result<()> {
     }
     // end-list-colls
 
+    // begin-create-custom-buckets
+    let ts_opts = TimeseriesOptions::builder()
+        .time_field("timestamp".to_string())
+        .meta_field(Some("sensor".to_string()))
+        .bucket_max_span(Some(Duration::from_secs(86400)))
+        .bucket_rounding(Some(Duration::from_secs(86400)))
+        .build();
+
+    db.create_collection("weather")
+        .timeseries(ts_opts)
+        .await?;
+    // end-create-custom-buckets
+
+    // begin-query-range
+    let my_coll: Collection<Reading> = db.collection("weather");
+
+    let start = DateTime::builder().year(2023).month(9).day(1).build().unwrap();
+    let end = DateTime::builder().year(2023).month(9).day(8).build().unwrap();
+    let filter = doc! {
+        "sensor": "station-1",
+        "timestamp": doc! { "$gte": start, "$lt": end }
+    };
+
+    let mut cursor = my_coll.find(filter).sort(doc! { "timestamp": 1 }).await?;
+    while let Some(reading) = cursor.try_next().await? {
+        println!("{:?}", reading);
+    }
+    // end-query-range
+
+    // begin-downsample
+    let start = DateTime::builder().year(2023).month(9).day(1).build().unwrap();
+    let end = DateTime::builder().year(2023).month(10).day(1).build().unwrap();
+    let pipeline = vec![
+        doc! { "$match": doc! { "timestamp": doc! { "$gte": start, "$lt": end } } },
+        doc! { "$group": doc! {
+            "_id": doc! {
+                "sensor": "$sensor",
+                "day": doc! { "$dateTrunc": doc! { "date": "$timestamp", "unit": "day" } }
+            },
+            "total_mm": doc! { "$sum": "$precipitation_mm" }
+        } },
+        doc! { "$sort": doc! { "_id.day": 1 } }
+    ];
+
+    let mut results = my_coll.aggregate(pipeline).await?;
+    while let Some(result) = results.try_next().await? {
+        println!("{:?}", result);
+    }
+    // end-downsample
+
+    // begin-create-expiration
+    let ts_opts = TimeseriesOptions::builder()
+        .time_field("timestamp".to_string())
+        .meta_field(Some("sensor".to_string()))
+        .granularity(Some(TimeseriesGranularity::Hours))
+        .build();
+
+    db.create_collection("hourly_readings")
+        .timeseries(ts_opts)
+        .expire_after_seconds(Duration::from_secs(30 * 86400))
+        .await?;
+    // end-create-expiration
+
+    // begin-change-expiration
+    db.run_command(doc! {
+        "collMod": "weather",
+        "expireAfterSeconds": 90 * 86400
+    }).await?;
+    // end-change-expiration
+
+    // begin-archive
+    let cutoff = DateTime::from_millis(
+        DateTime::now().timestamp_millis() - 89 * 86400 * 1000
+    );
+    let pipeline = vec![
+        doc! { "$match": doc! { "timestamp": doc! { "$lt": cutoff } } },
+        doc! { "$merge": doc! {
+            "into": "weather_archive",
+            "whenMatched": "keepExisting",
+            "whenNotMatched": "insert"
+        } }
+    ];
+
+    my_coll.aggregate(pipeline).await?;
+    // end-archive
+
     Ok(())
 }